@@ -20,7 +20,7 @@ use std::sync::Mutex;
 use std::process;
 
 #[cfg(target_os = "linux")]
-use std::{fs, env};
+use std::{fs, env, path};
 
 #[cfg(target_os = "windows")]
 use winapi::um::debugapi;
@@ -55,9 +55,13 @@ fn already_entered() -> bool {
 /// Before spawning the debugger we examine the execution environment
 /// a bit to try to help users through any configuration errors.
 ///
+/// `term` overrides which terminal emulator is used to launch the
+/// debugger on linux (see `linux_launch_term`). It has no effect on
+/// macos, since Terminal.app is the only supported option there.
+///
 /// Don't use this directly.
 #[cfg(not(target_os = "windows"))]
-pub fn debug_here_unixy_impl(debugger: Option<&str>) {
+pub fn debug_here_unixy_impl(debugger: Option<&str>, term: Option<&str>) {
     if already_entered() {
         return;
     }
@@ -97,9 +101,9 @@ pub fn debug_here_unixy_impl(debugger: Option<&str>) {
     let looping = true;
 
     #[cfg(target_os = "linux")]
-    let launch_stat = linux_launch_term(debugger);
+    let launch_stat = linux_launch_term(debugger, term);
     #[cfg(any(target_os = "macos", mac_catalyst))]
-    let launch_stat = macos_launch_term(debugger);
+    let launch_stat = { let _ = term; macos_launch_term(debugger) };
 
     if let Err(e) = launch_stat {
         eprintln!("debug-here: {}", e);
@@ -210,9 +214,105 @@ fn linux_check() -> Result<(), String> {
     Ok(())
 }
 
+/// Configuration for a terminal emulator that debug-here knows how to
+/// launch on linux.
+#[cfg(target_os = "linux")]
+struct TermConfig {
+    /// The name of the terminal binary, as it would be found on $PATH.
+    bin: &'static str,
+    /// The flag (or flags) this terminal uses to run a command with
+    /// arguments, as opposed to starting an interactive shell.
+    exec_flag: &'static [&'static str],
+    /// Whether this terminal will correctly exec the debugger and its
+    /// arguments directly. Some terminals mangle a multi-argument `-e`
+    /// invocation, so they need to launch `debug-here-gdb-wrapper`
+    /// instead and get their arguments through the
+    /// `RUST_DEBUG_HERE_LIFELINE` environment variable.
+    direct: bool,
+}
+
+/// The terminal emulators debug-here knows how to launch out of the box,
+/// in auto-detection order. If you use something that isn't in this
+/// table, set `RUST_DEBUG_HERE_TERMINAL` (or pass `term = "..."` to
+/// `debug_here!()`) to tell debug-here how to drive it.
+#[cfg(target_os = "linux")]
+const KNOWN_TERMS: &[TermConfig] = &[
+    TermConfig { bin: "alacritty", exec_flag: &["-e"], direct: true },
+    TermConfig { bin: "kitty", exec_flag: &["-e"], direct: true },
+    TermConfig { bin: "wezterm", exec_flag: &["start", "--"], direct: true },
+    TermConfig { bin: "gnome-terminal", exec_flag: &["--"], direct: false },
+    TermConfig { bin: "konsole", exec_flag: &["-e"], direct: false },
+    TermConfig { bin: "xterm", exec_flag: &["-e"], direct: false },
+];
+
+/// Look up the `TermConfig` for a terminal binary by name, matching on
+/// the end of the path so that `/usr/bin/alacritty` matches `alacritty`.
+#[cfg(target_os = "linux")]
+fn known_term_config(bin: &str) -> Option<&'static TermConfig> {
+    KNOWN_TERMS.iter().find(|t| bin.ends_with(t.bin))
+}
+
+/// Figure out which terminal emulator to launch, and how to launch it.
+///
+/// `term_override` takes priority if given (this is how the `term`
+/// argument to `debug_here!()` is threaded through). Otherwise we fall
+/// back to the `RUST_DEBUG_HERE_TERMINAL` environment variable, which
+/// should be set to the terminal binary followed by the flag(s) it uses
+/// to run a command (for example `konsole -e` or `wezterm start --`).
+/// If neither is set, we auto-detect by walking `KNOWN_TERMS` and taking
+/// the first one we find on $PATH.
+#[cfg(target_os = "linux")]
+fn resolve_term(term_override: Option<&str>)
+    -> Result<(path::PathBuf, Vec<String>, bool), String> {
+    let env_override = env::var("RUST_DEBUG_HERE_TERMINAL").ok();
+    let spec = term_override.map(|s| s.to_string()).or(env_override);
+
+    if let Some(spec) = spec {
+        let mut parts = spec.split_whitespace();
+        let bin = parts.next().ok_or_else(|| format!(
+            "RUST_DEBUG_HERE_TERMINAL (or the `term` argument to \
+             debug_here!()) is empty."))?;
+        let given_flag: Vec<String> = parts.map(|s| s.to_string()).collect();
+        let known = known_term_config(bin);
+
+        let exec_flag = if !given_flag.is_empty() {
+            given_flag
+        } else if let Some(t) = known {
+            t.exec_flag.iter().map(|s| s.to_string()).collect()
+        } else {
+            vec!["-e".to_string()]
+        };
+        let direct = known.map(|t| t.direct).unwrap_or(false);
+
+        match which::which(bin) {
+            Ok(path) => Ok((path, exec_flag, direct)),
+            Err(_) => Err(format!(
+                "can't find the configured terminal '{}' on your path.",
+                bin)),
+        }
+    } else {
+        for t in KNOWN_TERMS {
+            if let Ok(path) = which::which(t.bin) {
+                return Ok((path,
+                    t.exec_flag.iter().map(|s| s.to_string()).collect(),
+                    t.direct));
+            }
+        }
+
+        Err(format!(r#"
+            can't find a supported terminal emulator on your path. Tried:
+            {}. You can set the RUST_DEBUG_HERE_TERMINAL environment
+            variable (or pass `term = "..."` to debug_here!()) to tell
+            debug-here about a different one.
+            "#, KNOWN_TERMS.iter().map(|t| t.bin)
+                    .collect::<Vec<_>>().join(", ")))
+    }
+}
+
 /// Launch a terminal in a linux environment
 #[cfg(target_os = "linux")]
-fn linux_launch_term(debugger: &str) -> Result<(), String> {
+fn linux_launch_term(debugger: &str, term_override: Option<&str>)
+    -> Result<(), String> {
     // Set up a magic environment variable telling debug-here-gdb-wrapper
     // where to enter the program to be debugged.
     //
@@ -235,25 +335,17 @@ fn linux_launch_term(debugger: &str) -> Result<(), String> {
             format!("2,{},{}", process::id(), debugger));
     }
 
-    let term = match which::which("alacritty").or(which::which("xterm")) {
-        Ok(t) => t,
-        Err(_) => {
-            return Err(format!(r#"
-                can't find alacritty or xterm on your path. Those are the
-                only terminal emulators currently supported on linux.
-                "#));
-        }
-    };
-    let term_cmd = term.clone();
+    let (term, exec_flag, direct) = resolve_term(term_override)?;
 
-    let mut cmd = process::Command::new(term_cmd);
+    let mut cmd = process::Command::new(term.clone());
     cmd.stdin(process::Stdio::null())
        .stdout(process::Stdio::null())
        .stderr(process::Stdio::null());
+    cmd.args(&exec_flag);
 
-    // Alacritty doesn't need the shim
-    if term.ends_with("alacritty") {
-        cmd.arg("-e");
+    // Terminals that can exec the debugger's argv directly don't need
+    // the shim.
+    if direct {
         cmd.arg(debugger);
         cmd.args(debugger_args(debugger));
     } else {
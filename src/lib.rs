@@ -36,17 +36,29 @@ pub mod internal;
 ///
 /// If you want to force a specific debugger backend, you can write
 /// `debug_here!(gdb)` or `debug_here!(lldb)`.
+///
+/// On linux, debug-here auto-detects a terminal emulator to launch the
+/// debugger in out of a built-in table (alacritty, kitty, wezterm,
+/// gnome-terminal, konsole, and xterm). If you use something else, or
+/// want to pick a specific one, write `debug_here!(term = "konsole -e")`,
+/// giving the terminal binary followed by the flag(s) it uses to run a
+/// command. This can also be set once for a whole program via the
+/// `RUST_DEBUG_HERE_TERMINAL` environment variable; the macro argument
+/// takes priority over the environment variable when both are given.
 #[cfg(not(target_os = "windows"))]
 #[macro_export]
 macro_rules! debug_here {
     () => {
-        ::debug_here::internal::debug_here_unixy_impl(None);
+        ::debug_here::internal::debug_here_unixy_impl(None, None);
     };
     ( gdb ) => {
-        ::debug_here::internal::debug_here_unixy_impl(Some("rust-gdb"));
+        ::debug_here::internal::debug_here_unixy_impl(Some("rust-gdb"), None);
     };
     ( lldb ) => {
-        ::debug_here::internal::debug_here_unixy_impl(Some("rust-lldb"));
+        ::debug_here::internal::debug_here_unixy_impl(Some("rust-lldb"), None);
+    };
+    ( term = $term:expr ) => {
+        ::debug_here::internal::debug_here_unixy_impl(None, Some($term));
     };
 }
 